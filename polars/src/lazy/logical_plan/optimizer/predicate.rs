@@ -1,52 +1,366 @@
 use crate::lazy::logical_plan::optimizer::check_down_node;
 use crate::lazy::prelude::*;
-use crate::lazy::utils::{
-    count_downtree_projections, expr_to_root_column, has_expr, rename_expr_root_name,
-};
+use crate::lazy::utils::{count_downtree_projections, expr_to_root_column};
 use crate::prelude::*;
-use ahash::RandomState;
-use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 // arbitrary constant to reduce reallocation.
 // don't expect more than 100 predicates.
-const HASHMAP_SIZE: usize = 100;
+const PREDICATE_CAPACITY: usize = 100;
 
-fn init_hashmap<K, V>() -> HashMap<K, V, RandomState> {
-    HashMap::with_capacity_and_hasher(HASHMAP_SIZE, RandomState::new())
+fn init_predicates() -> Vec<(Expr, HashSet<Arc<String>>)> {
+    Vec::with_capacity(PREDICATE_CAPACITY)
 }
 
-/// Don't overwrite predicates but combine them.
-fn insert_and_combine_predicate(
-    predicates_map: &mut HashMap<Arc<String>, Expr, RandomState>,
-    name: Arc<String>,
-    predicate: Expr,
-) {
-    let existing_predicate = predicates_map.entry(name).or_insert_with(|| lit(true));
-    *existing_predicate = existing_predicate.clone().and(predicate)
+/// Walk the expression tree and collect every column the expression depends on.
+/// This lets us decide whether a predicate can be pushed past a node by testing
+/// whether all of these columns are still available in that node's schema,
+/// instead of relying on a single (possibly non-existent) root column.
+fn expr_to_root_columns(expr: &Expr) -> HashSet<Arc<String>> {
+    let mut roots = HashSet::new();
+    expr_to_root_columns_impl(expr, &mut roots);
+    roots
+}
+
+fn expr_to_root_columns_impl(expr: &Expr, roots: &mut HashSet<Arc<String>>) {
+    use Expr::*;
+    match expr {
+        Column(name) => {
+            roots.insert(name.clone());
+        }
+        Alias(expr, _) => expr_to_root_columns_impl(expr, roots),
+        Not(expr) => expr_to_root_columns_impl(expr, roots),
+        IsNull(expr) => expr_to_root_columns_impl(expr, roots),
+        IsNotNull(expr) => expr_to_root_columns_impl(expr, roots),
+        Cast { expr, .. } => expr_to_root_columns_impl(expr, roots),
+        Sort { expr, .. } => expr_to_root_columns_impl(expr, roots),
+        Reverse(expr) => expr_to_root_columns_impl(expr, roots),
+        Duplicated(expr) => expr_to_root_columns_impl(expr, roots),
+        IsUnique(expr) => expr_to_root_columns_impl(expr, roots),
+        Shift { input, .. } => expr_to_root_columns_impl(input, roots),
+        Slice { input, .. } => expr_to_root_columns_impl(input, roots),
+        AggQuantile { expr, .. } => expr_to_root_columns_impl(expr, roots),
+        AggMin(expr) | AggMax(expr) | AggMedian(expr) | AggNUnique(expr) | AggFirst(expr)
+        | AggLast(expr) | AggMean(expr) | AggList(expr) | AggSum(expr) | AggGroups(expr)
+        | AggCount(expr) => expr_to_root_columns_impl(expr, roots),
+        BinaryExpr { left, right, .. } => {
+            expr_to_root_columns_impl(left, roots);
+            expr_to_root_columns_impl(right, roots);
+        }
+        Ternary {
+            predicate,
+            truthy,
+            falsy,
+        } => {
+            expr_to_root_columns_impl(predicate, roots);
+            expr_to_root_columns_impl(truthy, roots);
+            expr_to_root_columns_impl(falsy, roots);
+        }
+        Apply { input, .. } => expr_to_root_columns_impl(input, roots),
+        Literal(_) | Wildcard => {}
+    }
 }
 
-pub struct PredicatePushDown {
-    // used in has_expr check. This reduces box allocations
-    unique_dummy: Expr,
-    duplicated_dummy: Expr,
-    binary_dummy: Expr,
-    is_null_dummy: Expr,
-    is_not_null_dummy: Expr,
+/// Rebuild `expr`, replacing every `Column(old_name)` node with `Column(new_name)` and leaving
+/// every other column reference untouched. Unlike `rename_expr_root_name`, this is safe to use
+/// on predicates that depend on more than one column: it only ever swaps the matching leaves.
+fn rename_column_in_expr(expr: &Expr, old_name: &Arc<String>, new_name: &Arc<String>) -> Expr {
+    let mut renamed = expr.clone();
+    rename_column_in_expr_mut(&mut renamed, old_name, new_name);
+    renamed
 }
 
-impl Default for PredicatePushDown {
-    fn default() -> Self {
-        PredicatePushDown {
-            unique_dummy: lit("_").is_unique(),
-            duplicated_dummy: lit("_").is_duplicated(),
-            binary_dummy: lit("_").eq(lit("_")),
-            is_null_dummy: lit("_").is_null(),
-            is_not_null_dummy: lit("_").is_null(),
+fn rename_column_in_expr_mut(expr: &mut Expr, old_name: &Arc<String>, new_name: &Arc<String>) {
+    use Expr::*;
+    match expr {
+        Column(name) => {
+            if name == old_name {
+                *name = new_name.clone();
+            }
         }
+        Alias(e, _) | Not(e) | IsNull(e) | IsNotNull(e) | Reverse(e) | Duplicated(e)
+        | IsUnique(e) => rename_column_in_expr_mut(e, old_name, new_name),
+        Cast { expr, .. } | Sort { expr, .. } | AggQuantile { expr, .. } => {
+            rename_column_in_expr_mut(expr, old_name, new_name)
+        }
+        Shift { input, .. } | Slice { input, .. } | Apply { input, .. } => {
+            rename_column_in_expr_mut(input, old_name, new_name)
+        }
+        AggMin(e) | AggMax(e) | AggMedian(e) | AggNUnique(e) | AggFirst(e) | AggLast(e)
+        | AggMean(e) | AggList(e) | AggSum(e) | AggGroups(e) | AggCount(e) => {
+            rename_column_in_expr_mut(e, old_name, new_name)
+        }
+        BinaryExpr { left, right, .. } => {
+            rename_column_in_expr_mut(left, old_name, new_name);
+            rename_column_in_expr_mut(right, old_name, new_name);
+        }
+        Ternary {
+            predicate,
+            truthy,
+            falsy,
+        } => {
+            rename_column_in_expr_mut(predicate, old_name, new_name);
+            rename_column_in_expr_mut(truthy, old_name, new_name);
+            rename_column_in_expr_mut(falsy, old_name, new_name);
+        }
+        Literal(_) | Wildcard => {}
     }
 }
 
+/// Rename any accumulated predicate that refers to one of `expr`'s aliases to the aliased
+/// expression's own root column, so it can keep being pushed down past this projection.
+/// Predicates that don't touch any alias are left untouched. An alias over a compound
+/// expression (e.g. `(col("a") + col("b")).alias("sum")`) has no single underlying column to
+/// rename to, so a predicate depending on it is simply left unrenamed rather than panicking.
+fn rename_aliased_predicates(acc_predicates: &mut Vec<(Expr, HashSet<Arc<String>>)>, expr: &[Expr]) {
+    for e in expr {
+        // check if there is an alias
+        if let Expr::Alias(e, name) = e {
+            // only resolve the aliased expression's root column if some predicate actually
+            // depends on this alias; avoids both wasted work and spurious errors below.
+            if !acc_predicates.iter().any(|(_, columns)| columns.contains(name)) {
+                continue;
+            }
+            let new_name = match expr_to_root_column(e) {
+                Ok(new_name) => new_name,
+                Err(_) => continue,
+            };
+            for (predicate, columns) in acc_predicates.iter_mut() {
+                if columns.remove(name) {
+                    columns.insert(new_name.clone());
+                    // rename only the `Column(name)` leaves that refer to this
+                    // alias; a predicate may depend on several other columns
+                    // that must be left untouched (e.g. `col("a") + col("b") > 5`).
+                    *predicate = rename_column_in_expr(predicate, name, &new_name);
+                }
+            }
+        }
+    }
+}
+
+/// Translate `acc_predicates` from the union's output schema to one branch's own schema,
+/// matching fields positionally: a union only guarantees its branches line up column-for-
+/// column, not that they share names. Fields whose name doesn't change are left alone.
+fn rename_predicates_for_schema(
+    acc_predicates: Vec<(Expr, HashSet<Arc<String>>)>,
+    union_schema: &Schema,
+    branch_schema: &Schema,
+) -> Vec<(Expr, HashSet<Arc<String>>)> {
+    let renames: Vec<(Arc<String>, Arc<String>)> = union_schema
+        .fields()
+        .iter()
+        .zip(branch_schema.fields().iter())
+        .filter_map(|(union_field, branch_field)| {
+            let union_name = union_field.name();
+            let branch_name = branch_field.name();
+            if union_name != branch_name {
+                Some((Arc::new(union_name.clone()), Arc::new(branch_name.clone())))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    if renames.is_empty() {
+        return acc_predicates;
+    }
+
+    acc_predicates
+        .into_iter()
+        .map(|(mut predicate, mut columns)| {
+            // rename through placeholders first, so that swapped or chained names
+            // (`a -> b` together with `b -> a`) are each translated exactly once.
+            let mut placed = Vec::with_capacity(renames.len());
+            for (i, (old_name, new_name)) in renames.iter().enumerate() {
+                if columns.remove(old_name) {
+                    let placeholder = Arc::new(format!("\u{0}union-rename-{}", i));
+                    predicate = rename_column_in_expr(&predicate, old_name, &placeholder);
+                    placed.push((placeholder, new_name));
+                }
+            }
+            for (placeholder, new_name) in placed {
+                columns.insert(new_name.clone());
+                predicate = rename_column_in_expr(&predicate, &placeholder, new_name);
+            }
+            (predicate, columns)
+        })
+        .collect()
+}
+
+/// A predicate can be pushed further down a node if every column it depends on
+/// is still part of that node's schema.
+fn all_columns_available(columns: &HashSet<Arc<String>>, schema: &Schema) -> bool {
+    columns.iter().all(|name| schema.field_with_name(name).is_ok())
+}
+
+/// Whether a predicate may be pushed through this operation without changing the result.
+/// Operations that only reorder rows commute with filtering; anything that changes the row
+/// count (head/tail/slice/limit/sample, ...) or the column names a predicate above it refers
+/// to (rename) does not, and must act as a pushdown boundary. Unknown operations default to
+/// non-commutative: that is always safe, it just misses an optimization instead of risking a
+/// wrong result.
+fn is_filter_commutative(op: &DataFrameOperation) -> bool {
+    use DataFrameOperation::*;
+    match op {
+        Sort { .. } | Reverse => true,
+        // Rename changes column *names*, so a predicate accumulated above it refers to the
+        // post-rename name and can't be pushed into the input unchanged: it would either
+        // error or, worse, silently match a differently-named column that happens to exist.
+        _ => false,
+    }
+}
+
+/// If `predicate` is a simple equality between a column available on `schema_left` and a
+/// column available on `schema_right`, return `(left_expr, right_expr)` oriented so they can
+/// be folded straight into the join's `left_on`/`right_on` conditions.
+fn equi_join_condition(
+    predicate: &Expr,
+    schema_left: &Schema,
+    schema_right: &Schema,
+) -> Option<(Expr, Expr)> {
+    if let Expr::BinaryExpr { left, op, right } = predicate {
+        if *op != Operator::Eq {
+            return None;
+        }
+        if check_down_node(left, schema_left) && check_down_node(right, schema_right) {
+            return Some((left.as_ref().clone(), right.as_ref().clone()));
+        }
+        if check_down_node(left, schema_right) && check_down_node(right, schema_left) {
+            return Some((right.as_ref().clone(), left.as_ref().clone()));
+        }
+    }
+    None
+}
+
+/// How thoroughly the CSV scan can apply a predicate pushed into it while reading.
+enum Exactness {
+    /// The per-batch evaluator fully evaluates the predicate: it can be dropped from the plan.
+    Exact,
+    /// The scan applies the predicate best-effort; a residual `Selection` must stay in the
+    /// plan to catch anything that isn't guaranteed to be filtered out.
+    Inexact,
+}
+
+/// Whether the CSV per-batch evaluator can be trusted to evaluate `expr` exactly while
+/// scanning. UDFs (`Apply`) and branching (`Ternary`) aren't guaranteed to be supported, so
+/// any predicate containing one is only ever applied best-effort.
+fn csv_predicate_exactness(expr: &Expr) -> Exactness {
+    if contains_inexact_for_csv(expr) {
+        Exactness::Inexact
+    } else {
+        Exactness::Exact
+    }
+}
+
+fn contains_inexact_for_csv(expr: &Expr) -> bool {
+    use Expr::*;
+    match expr {
+        Apply { .. } | Ternary { .. } => true,
+        Column(_) | Literal(_) | Wildcard => false,
+        Alias(e, _) | Not(e) | IsNull(e) | IsNotNull(e) | Reverse(e) | Duplicated(e)
+        | IsUnique(e) => contains_inexact_for_csv(e),
+        Cast { expr, .. } | Sort { expr, .. } | AggQuantile { expr, .. } => {
+            contains_inexact_for_csv(expr)
+        }
+        Shift { input, .. } | Slice { input, .. } => contains_inexact_for_csv(input),
+        AggMin(e) | AggMax(e) | AggMedian(e) | AggNUnique(e) | AggFirst(e) | AggLast(e)
+        | AggMean(e) | AggList(e) | AggSum(e) | AggGroups(e) | AggCount(e) => {
+            contains_inexact_for_csv(e)
+        }
+        BinaryExpr { left, right, .. } => {
+            contains_inexact_for_csv(left) || contains_inexact_for_csv(right)
+        }
+    }
+}
+
+/// Structural classification of a predicate, used to decide whether it may cross a
+/// `Distinct`/`Join` boundary. Computed with a single tree walk instead of matching the
+/// predicate against a handful of hand-built dummy expressions.
+struct PredicateKind {
+    contains_unique_or_duplicated: bool,
+    contains_is_null: bool,
+    contains_binary_cross_column: bool,
+}
+
+fn classify_predicate(expr: &Expr) -> PredicateKind {
+    let mut kind = PredicateKind {
+        contains_unique_or_duplicated: false,
+        contains_is_null: false,
+        contains_binary_cross_column: false,
+    };
+    classify_predicate_impl(expr, &mut kind);
+    kind
+}
+
+fn classify_predicate_impl(expr: &Expr, kind: &mut PredicateKind) {
+    use Expr::*;
+    match expr {
+        IsUnique(e) | Duplicated(e) => {
+            kind.contains_unique_or_duplicated = true;
+            classify_predicate_impl(e, kind);
+        }
+        IsNull(e) | IsNotNull(e) => {
+            kind.contains_is_null = true;
+            classify_predicate_impl(e, kind);
+        }
+        BinaryExpr { left, right, .. } => {
+            // a binary node only relates two different tables/rows if it actually spans more
+            // than one column (`a > b`), not when one side is a literal (`a > 5`).
+            let mut columns = HashSet::new();
+            expr_to_root_columns_impl(left, &mut columns);
+            expr_to_root_columns_impl(right, &mut columns);
+            if columns.len() > 1 {
+                kind.contains_binary_cross_column = true;
+            }
+            classify_predicate_impl(left, kind);
+            classify_predicate_impl(right, kind);
+        }
+        Alias(e, _) | Not(e) | Reverse(e) => classify_predicate_impl(e, kind),
+        Cast { expr, .. } | Sort { expr, .. } | AggQuantile { expr, .. } => {
+            classify_predicate_impl(expr, kind)
+        }
+        Shift { input, .. } | Slice { input, .. } | Apply { input, .. } => {
+            classify_predicate_impl(input, kind)
+        }
+        AggMin(e) | AggMax(e) | AggMedian(e) | AggNUnique(e) | AggFirst(e) | AggLast(e)
+        | AggMean(e) | AggList(e) | AggSum(e) | AggGroups(e) | AggCount(e) => {
+            classify_predicate_impl(e, kind)
+        }
+        Ternary {
+            predicate,
+            truthy,
+            falsy,
+        } => {
+            classify_predicate_impl(predicate, kind);
+            classify_predicate_impl(truthy, kind);
+            classify_predicate_impl(falsy, kind);
+        }
+        Column(_) | Literal(_) | Wildcard => {}
+    }
+}
+
+/// Don't overwrite predicates but combine them. Predicates that depend on exactly
+/// the same set of columns are combined (ANDed) into a single entry.
+fn insert_and_combine_predicate(
+    acc_predicates: &mut Vec<(Expr, HashSet<Arc<String>>)>,
+    columns: HashSet<Arc<String>>,
+    predicate: Expr,
+) {
+    match acc_predicates
+        .iter_mut()
+        .find(|(_, existing_columns)| existing_columns == &columns)
+    {
+        Some((existing_predicate, _)) => {
+            *existing_predicate = existing_predicate.clone().and(predicate)
+        }
+        None => acc_predicates.push((predicate, columns)),
+    }
+}
+
+#[derive(Default)]
+pub struct PredicatePushDown {}
+
 pub(crate) fn combine_predicates<I>(iter: I) -> Expr
 where
     I: Iterator<Item = Expr>,
@@ -65,7 +379,7 @@ impl PredicatePushDown {
     fn finish_at_leaf(
         &self,
         lp: LogicalPlan,
-        acc_predicates: HashMap<Arc<String>, Expr, RandomState>,
+        acc_predicates: Vec<(Expr, HashSet<Arc<String>>)>,
     ) -> Result<LogicalPlan> {
         match acc_predicates.len() {
             // No filter in the logical plan
@@ -73,7 +387,7 @@ impl PredicatePushDown {
             _ => {
                 let mut builder = LogicalPlanBuilder::from(lp);
 
-                let predicate = combine_predicates(acc_predicates.values().cloned());
+                let predicate = combine_predicates(acc_predicates.into_iter().map(|(e, _)| e));
                 builder = builder.filter(predicate);
                 Ok(builder.build())
             }
@@ -94,60 +408,30 @@ impl PredicatePushDown {
         }
     }
 
-    // acc predicates maps the root column names to predicates
+    // acc predicates are paired with the full set of columns they depend on
     fn push_down(
         &self,
         logical_plan: LogicalPlan,
-        mut acc_predicates: HashMap<Arc<String>, Expr, RandomState>,
+        mut acc_predicates: Vec<(Expr, HashSet<Arc<String>>)>,
     ) -> Result<LogicalPlan> {
         use LogicalPlan::*;
 
         match logical_plan {
             Selection { predicate, input } => {
-                match expr_to_root_column(&predicate) {
-                    Ok(name) => insert_and_combine_predicate(&mut acc_predicates, name, predicate),
-                    Err(e) => {
-                        if let Expr::BinaryExpr { left, right, .. } = &predicate {
-                            let left_name = expr_to_root_column(&*left)?;
-                            let right_name = expr_to_root_column(&*right)?;
-                            let name = Arc::new(format!("{}-binary-{}", left_name, right_name));
-                            insert_and_combine_predicate(&mut acc_predicates, name, predicate);
-                        } else {
-                            panic!(format!("{:?}", e))
-                        }
-                    }
-                }
+                let columns = expr_to_root_columns(&predicate);
+                insert_and_combine_predicate(&mut acc_predicates, columns, predicate);
                 self.push_down(*input, acc_predicates)
             }
             Projection { expr, input, .. } => {
                 // don't filter before the last projection that is more expensive as projections are free
                 if count_downtree_projections(&input, 0) == 0 {
-                    let builder = LogicalPlanBuilder::from(self.push_down(
-                        *input,
-                        HashMap::with_capacity_and_hasher(HASHMAP_SIZE, RandomState::new()),
-                    )?)
-                    .project(expr);
-                    // todo! write utility that takes hashmap values by value
-                    self.finish_node(acc_predicates.values().cloned().collect(), builder)
+                    let builder =
+                        LogicalPlanBuilder::from(self.push_down(*input, init_predicates())?)
+                            .project(expr);
+                    self.finish_node(acc_predicates.into_iter().map(|(e, _)| e).collect(), builder)
                 } else {
                     // maybe update predicate name if a projection is an alias
-                    for e in &expr {
-                        // check if there is an alias
-                        if let Expr::Alias(e, name) = e {
-                            // if this alias refers to one of the predicates in the upper nodes
-                            // we rename the column of the predicate before we push it downwards.
-                            if let Some(predicate) = acc_predicates.remove(name) {
-                                let new_name = expr_to_root_column(e).unwrap();
-                                let new_predicate =
-                                    rename_expr_root_name(&predicate, new_name.clone()).unwrap();
-                                insert_and_combine_predicate(
-                                    &mut acc_predicates,
-                                    new_name,
-                                    new_predicate,
-                                );
-                            }
-                        }
-                    }
+                    rename_aliased_predicates(&mut acc_predicates, &expr);
                     Ok(
                         LogicalPlanBuilder::from(self.push_down(*input, acc_predicates)?)
                             .project(expr)
@@ -178,7 +462,28 @@ impl PredicatePushDown {
                 skip_rows,
                 stop_after_n_rows,
                 with_columns,
+                predicate: _,
             } => {
+                // Any predicate whose columns are all present in the scan schema can be handed
+                // to the scan. Exact predicates are fully evaluated row by row as the scan reads
+                // each batch, so they are dropped from the plan; anything the per-batch evaluator
+                // can't guarantee to evaluate exactly (e.g. a UDF `Apply`, or a `Ternary`) is
+                // still handed to the scan as a best-effort filter, but a residual `Selection`
+                // is kept above it to catch whatever leaks through. Columns not produced by this
+                // scan are emitted locally, above the scan, same as before.
+                let (mut local, pushdown) = self.split_pushdown_and_local(acc_predicates, &schema);
+                let mut scan_predicates = Vec::with_capacity(pushdown.len());
+                for (predicate, _) in pushdown {
+                    if let Exactness::Inexact = csv_predicate_exactness(&predicate) {
+                        local.push(predicate.clone());
+                    }
+                    scan_predicates.push(predicate);
+                }
+                let predicate = if scan_predicates.is_empty() {
+                    None
+                } else {
+                    Some(combine_predicates(scan_predicates.into_iter()))
+                };
                 let lp = CsvScan {
                     path,
                     schema,
@@ -188,15 +493,27 @@ impl PredicatePushDown {
                     skip_rows,
                     stop_after_n_rows,
                     with_columns,
+                    predicate,
                 };
-                self.finish_at_leaf(lp, acc_predicates)
+                self.finish_node(local, LogicalPlanBuilder::from(lp))
             }
             DataFrameOp { input, operation } => {
-                let input = self.push_down(*input, acc_predicates)?;
-                Ok(DataFrameOp {
-                    input: Box::new(input),
-                    operation,
-                })
+                if is_filter_commutative(&operation) {
+                    let input = self.push_down(*input, acc_predicates)?;
+                    Ok(DataFrameOp {
+                        input: Box::new(input),
+                        operation,
+                    })
+                } else {
+                    // row-count-sensitive operations (head/tail/slice/limit/sample, ...) are a
+                    // pushdown boundary: filter(limit(df)) != limit(filter(df)), so the
+                    // accumulated predicates must be applied locally, above this op.
+                    let lp = DataFrameOp {
+                        input: Box::new(self.push_down(*input, init_predicates())?),
+                        operation,
+                    };
+                    self.finish_at_leaf(lp, acc_predicates)
+                }
             }
             Distinct {
                 input,
@@ -208,12 +525,12 @@ impl PredicatePushDown {
                 // contain a binary expression (thus depending on values in multiple columns) the final result may differ if it is pushed down.
                 let mut local_pred = Vec::with_capacity(acc_predicates.len());
 
-                let mut new_acc_predicates = init_hashmap();
-                for (name, predicate) in acc_predicates {
-                    if has_expr(&predicate, &self.binary_dummy) {
+                let mut new_acc_predicates = init_predicates();
+                for (predicate, columns) in acc_predicates {
+                    if classify_predicate(&predicate).contains_binary_cross_column {
                         local_pred.push(predicate)
                     } else {
-                        new_acc_predicates.insert(name, predicate);
+                        new_acc_predicates.push((predicate, columns));
                     }
                 }
 
@@ -238,7 +555,7 @@ impl PredicatePushDown {
             } => {
                 // dont push down predicates. An aggregation needs all rows
                 let lp = Aggregate {
-                    input: Box::new(self.push_down(*input, init_hashmap())?),
+                    input: Box::new(self.push_down(*input, init_predicates())?),
                     keys,
                     aggs,
                     schema,
@@ -248,25 +565,23 @@ impl PredicatePushDown {
             Join {
                 input_left,
                 input_right,
-                left_on,
-                right_on,
+                mut left_on,
+                mut right_on,
                 how,
                 ..
             } => {
                 let schema_left = input_left.schema();
                 let schema_right = input_right.schema();
 
-                let mut pushdown_left = init_hashmap();
-                let mut pushdown_right = init_hashmap();
+                let mut pushdown_left = init_predicates();
+                let mut pushdown_right = init_predicates();
                 let mut local_predicates = Vec::with_capacity(acc_predicates.len());
+                let mut on_filters = Vec::with_capacity(acc_predicates.len());
 
-                for (_, predicate) in acc_predicates {
+                for (predicate, columns) in acc_predicates {
+                    let kind = classify_predicate(&predicate);
                     // unique and duplicated can be caused by joins
-                    if has_expr(&predicate, &self.unique_dummy) {
-                        local_predicates.push(predicate.clone());
-                        continue;
-                    }
-                    if has_expr(&predicate, &self.duplicated_dummy) {
+                    if kind.contains_unique_or_duplicated {
                         local_predicates.push(predicate.clone());
                         continue;
                     }
@@ -275,40 +590,70 @@ impl PredicatePushDown {
 
                     // no else if. predicate can be in both tables.
                     if check_down_node(&predicate, schema_left) {
-                        let name =
-                            Arc::new(predicate.to_field(schema_left).unwrap().name().clone());
-                        insert_and_combine_predicate(&mut pushdown_left, name, predicate.clone());
+                        insert_and_combine_predicate(
+                            &mut pushdown_left,
+                            columns.clone(),
+                            predicate.clone(),
+                        );
                         filter_left = true;
                     }
                     if check_down_node(&predicate, schema_right) {
-                        let name =
-                            Arc::new(predicate.to_field(schema_right).unwrap().name().clone());
-                        insert_and_combine_predicate(&mut pushdown_right, name, predicate.clone());
+                        insert_and_combine_predicate(
+                            &mut pushdown_right,
+                            columns.clone(),
+                            predicate.clone(),
+                        );
                         filter_right = true;
                     }
                     if !(filter_left & filter_right) {
+                        // references columns from both inputs (and isn't wholesale available on
+                        // either side, e.g. a join key that isn't duplicated under the same name).
+                        // Rather than defer to a post-join `Selection` over a potentially huge
+                        // join product, fold it into the join itself.
+                        let touches_left =
+                            columns.iter().any(|c| schema_left.field_with_name(c).is_ok());
+                        let touches_right =
+                            columns.iter().any(|c| schema_right.field_with_name(c).is_ok());
+                        if touches_left && touches_right {
+                            // an outer/left join can introduce nulls on either side; a predicate
+                            // that actually inspects nullability must stay local, same as below.
+                            let keep_local_for_nulls = (how == JoinType::Outer
+                                || how == JoinType::Left)
+                                && kind.contains_is_null;
+                            if !keep_local_for_nulls {
+                                match equi_join_condition(&predicate, schema_left, schema_right) {
+                                    Some((l, r)) => {
+                                        left_on.push(l);
+                                        right_on.push(r);
+                                    }
+                                    None => on_filters.push(predicate),
+                                }
+                                continue;
+                            }
+                        }
                         local_predicates.push(predicate.clone());
                         continue;
                     }
                     // An outer join or left join may create null values.
                     // we also do it local
-                    if (how == JoinType::Outer) | (how == JoinType::Left) {
-                        if has_expr(&predicate, &self.is_not_null_dummy) {
-                            local_predicates.push(predicate.clone());
-                            continue;
-                        }
-                        if has_expr(&predicate, &self.is_null_dummy) {
-                            local_predicates.push(predicate);
-                            continue;
-                        }
+                    if ((how == JoinType::Outer) | (how == JoinType::Left)) && kind.contains_is_null
+                    {
+                        local_predicates.push(predicate);
+                        continue;
                     }
                 }
 
                 let lp_left = self.push_down(*input_left, pushdown_left)?;
                 let lp_right = self.push_down(*input_right, pushdown_right)?;
 
-                let builder =
-                    LogicalPlanBuilder::from(lp_left).join(lp_right, how, left_on, right_on);
+                let on_filter = if on_filters.is_empty() {
+                    None
+                } else {
+                    Some(combine_predicates(on_filters.into_iter()))
+                };
+
+                let builder = LogicalPlanBuilder::from(lp_left)
+                    .join(lp_right, how, left_on, right_on, on_filter);
                 self.finish_node(local_predicates, builder)
             }
             HStack { input, exprs, .. } => {
@@ -324,34 +669,201 @@ impl PredicatePushDown {
                 }
                 Ok(lp_builder.build())
             }
+            Union { inputs, schema } => {
+                // A predicate that holds for the union holds independently for each branch, so
+                // push a copy into every input instead of filtering once above the
+                // concatenation. Branches only need to align with the union's schema
+                // positionally, not by name, so translate each predicate's column references
+                // through that positional mapping before pushing it into that branch. Every
+                // branch absorbs the (possibly renamed) predicates fully at its own leaves, so
+                // no local filter is needed here.
+                let new_inputs = inputs
+                    .into_iter()
+                    .map(|input| {
+                        let branch_schema = input.schema();
+                        let branch_predicates =
+                            rename_predicates_for_schema(acc_predicates.clone(), &schema, branch_schema);
+                        self.push_down(input, branch_predicates)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Union {
+                    inputs: new_inputs,
+                    schema,
+                })
+            }
         }
     }
 
-    /// Check if a predicate can be pushed down or not. If it cannot remove it from the accumulated predicates.
+    /// Split the accumulated predicates into the ones that can be pushed further down a node
+    /// (every column they depend on is present in `schema`) and the ones that must be applied
+    /// locally, right above that node.
     fn split_pushdown_and_local(
         &self,
-        mut acc_predicates: HashMap<Arc<String>, Expr, RandomState>,
+        acc_predicates: Vec<(Expr, HashSet<Arc<String>>)>,
         schema: &Schema,
-    ) -> (Vec<Expr>, HashMap<Arc<String>, Expr, RandomState>) {
+    ) -> (Vec<Expr>, Vec<(Expr, HashSet<Arc<String>>)>) {
         let mut local = Vec::with_capacity(acc_predicates.len());
-        let mut local_keys = Vec::with_capacity(acc_predicates.len());
-        for (key, predicate) in &acc_predicates {
-            if !check_down_node(predicate, schema) {
-                local_keys.push(key.clone());
+        let mut pushdown = Vec::with_capacity(acc_predicates.len());
+        for (predicate, columns) in acc_predicates {
+            if all_columns_available(&columns, schema) {
+                pushdown.push((predicate, columns));
+            } else {
+                local.push(predicate);
             }
         }
-        for key in local_keys {
-            local.push(acc_predicates.remove(&key).unwrap());
-        }
-        (local, acc_predicates)
+        (local, pushdown)
     }
 }
 
 impl Optimize for PredicatePushDown {
     fn optimize(&self, logical_plan: LogicalPlan) -> Result<LogicalPlan> {
-        self.push_down(
-            logical_plan,
-            HashMap::with_capacity_and_hasher(HASHMAP_SIZE, RandomState::new()),
+        self.push_down(logical_plan, init_predicates())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn col(name: &str) -> Expr {
+        Expr::Column(Arc::new(name.to_string()))
+    }
+
+    fn eq(left: Expr, right: Expr) -> Expr {
+        Expr::BinaryExpr {
+            left: Box::new(left),
+            op: Operator::Eq,
+            right: Box::new(right),
+        }
+    }
+
+    fn alias(expr: Expr, name: &str) -> Expr {
+        Expr::Alias(Box::new(expr), Arc::new(name.to_string()))
+    }
+
+    fn schema(names: &[&str]) -> Schema {
+        Schema::new(
+            names
+                .iter()
+                .map(|name| Field::new(*name, DataType::Int32))
+                .collect(),
         )
     }
+
+    fn acc(predicate: Expr, columns: &[&str]) -> Vec<(Expr, HashSet<Arc<String>>)> {
+        vec![(
+            predicate,
+            columns.iter().map(|c| Arc::new(c.to_string())).collect(),
+        )]
+    }
+
+    // chunk0-1: Projection alias rename
+    #[test]
+    fn rename_aliased_predicates_renames_matching_predicate() {
+        let mut acc_predicates = acc(col("sum"), &["sum"]);
+        let expr = vec![alias(col("a"), "sum")];
+
+        rename_aliased_predicates(&mut acc_predicates, &expr);
+
+        let (predicate, columns) = &acc_predicates[0];
+        assert!(columns.contains(&Arc::new("a".to_string())));
+        assert!(!columns.contains(&Arc::new("sum".to_string())));
+        assert_eq!(expr_to_root_columns(predicate), *columns);
+    }
+
+    #[test]
+    fn rename_aliased_predicates_leaves_unrelated_predicates_untouched() {
+        let mut acc_predicates = acc(col("b"), &["b"]);
+        let expr = vec![alias(col("a"), "sum")];
+
+        rename_aliased_predicates(&mut acc_predicates, &expr);
+
+        let (_, columns) = &acc_predicates[0];
+        assert!(columns.contains(&Arc::new("b".to_string())));
+    }
+
+    #[test]
+    fn rename_aliased_predicates_does_not_panic_on_compound_alias_without_matching_predicate() {
+        // `(col("a")+col("b")).alias("sum")` has no single root column; with no predicate
+        // referencing "sum" the alias is skipped without trying to resolve it.
+        let mut acc_predicates: Vec<(Expr, HashSet<Arc<String>>)> = Vec::new();
+        let expr = vec![alias(eq(col("a"), col("b")), "sum")];
+
+        rename_aliased_predicates(&mut acc_predicates, &expr);
+
+        assert!(acc_predicates.is_empty());
+    }
+
+    #[test]
+    fn rename_aliased_predicates_skips_compound_alias_with_matching_predicate() {
+        // A predicate on a compound alias can't be resolved to a single underlying column;
+        // it's left unrenamed rather than panicking.
+        let mut acc_predicates = acc(col("sum"), &["sum"]);
+        let expr = vec![alias(eq(col("a"), col("b")), "sum")];
+
+        rename_aliased_predicates(&mut acc_predicates, &expr);
+
+        let (_, columns) = &acc_predicates[0];
+        assert!(columns.contains(&Arc::new("sum".to_string())));
+    }
+
+    // chunk0-4: Join cross-predicate folding
+    #[test]
+    fn equi_join_condition_matches_left_to_right_orientation() {
+        let schema_left = schema(&["a"]);
+        let schema_right = schema(&["b"]);
+        let predicate = eq(col("a"), col("b"));
+
+        let (left, right) = equi_join_condition(&predicate, &schema_left, &schema_right).unwrap();
+        assert_eq!(expr_to_root_columns(&left), acc(col("a"), &["a"])[0].1);
+        assert_eq!(expr_to_root_columns(&right), acc(col("b"), &["b"])[0].1);
+    }
+
+    #[test]
+    fn equi_join_condition_matches_reversed_orientation() {
+        let schema_left = schema(&["a"]);
+        let schema_right = schema(&["b"]);
+        let predicate = eq(col("b"), col("a"));
+
+        let (left, right) = equi_join_condition(&predicate, &schema_left, &schema_right).unwrap();
+        assert_eq!(expr_to_root_columns(&left), acc(col("a"), &["a"])[0].1);
+        assert_eq!(expr_to_root_columns(&right), acc(col("b"), &["b"])[0].1);
+    }
+
+    #[test]
+    fn equi_join_condition_returns_none_when_not_a_cross_table_predicate() {
+        let schema_left = schema(&["a"]);
+        let schema_right = schema(&["b"]);
+        let predicate = eq(col("a"), col("a2"));
+
+        assert!(equi_join_condition(&predicate, &schema_left, &schema_right).is_none());
+    }
+
+    // chunk0-5: Union branch renaming
+    #[test]
+    fn rename_predicates_for_schema_renames_only_differing_positions() {
+        let union_schema = schema(&["a", "b"]);
+        let branch_schema = schema(&["x", "b"]);
+        let acc_predicates = acc(eq(col("a"), col("b")), &["a", "b"]);
+
+        let renamed = rename_predicates_for_schema(acc_predicates, &union_schema, &branch_schema);
+
+        let (predicate, columns) = &renamed[0];
+        assert!(columns.contains(&Arc::new("x".to_string())));
+        assert!(columns.contains(&Arc::new("b".to_string())));
+        assert!(!columns.contains(&Arc::new("a".to_string())));
+        assert_eq!(expr_to_root_columns(predicate), *columns);
+    }
+
+    #[test]
+    fn rename_predicates_for_schema_is_a_no_op_when_branch_matches_union() {
+        let union_schema = schema(&["a", "b"]);
+        let branch_schema = schema(&["a", "b"]);
+        let acc_predicates = acc(col("a"), &["a"]);
+
+        let renamed = rename_predicates_for_schema(acc_predicates, &union_schema, &branch_schema);
+
+        let (_, columns) = &renamed[0];
+        assert!(columns.contains(&Arc::new("a".to_string())));
+    }
 }